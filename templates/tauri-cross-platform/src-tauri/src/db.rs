@@ -0,0 +1,93 @@
+// 永続化レイヤー：SQLite を使った records テーブルの CRUD ロジック。
+// Tauri から独立させてあるので、Connection さえ渡せば単体テストできる。
+
+use rusqlite::{params, Connection, Result as SqliteResult, Row};
+use serde_json::{json, Value};
+
+pub fn init_db(conn: &Connection) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS records (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            title TEXT NOT NULL,
+            body TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+pub fn create_record(conn: &Connection, title: &str, body: &str) -> SqliteResult<Value> {
+    conn.execute(
+        "INSERT INTO records (title, body) VALUES (?1, ?2)",
+        params![title, body],
+    )?;
+    get_record(conn, conn.last_insert_rowid())
+}
+
+pub fn list_records(conn: &Connection) -> SqliteResult<Value> {
+    let mut stmt = conn.prepare("SELECT id, title, body, created_at FROM records ORDER BY id")?;
+    let records = stmt
+        .query_map([], row_to_json)?
+        .collect::<SqliteResult<Vec<Value>>>()?;
+    Ok(json!(records))
+}
+
+pub fn get_record(conn: &Connection, id: i64) -> SqliteResult<Value> {
+    conn.query_row(
+        "SELECT id, title, body, created_at FROM records WHERE id = ?1",
+        params![id],
+        row_to_json,
+    )
+}
+
+pub fn delete_record(conn: &Connection, id: i64) -> SqliteResult<usize> {
+    conn.execute("DELETE FROM records WHERE id = ?1", params![id])
+}
+
+fn row_to_json(row: &Row) -> SqliteResult<Value> {
+    Ok(json!({
+        "id": row.get::<_, i64>(0)?,
+        "title": row.get::<_, String>(1)?,
+        "body": row.get::<_, String>(2)?,
+        "createdAt": row.get::<_, String>(3)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_conn() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        init_db(&conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn create_and_list_records() {
+        let conn = setup_conn();
+        create_record(&conn, "Title", "Body").unwrap();
+        let records = list_records(&conn).unwrap();
+        assert_eq!(records.as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn get_record_returns_created_fields() {
+        let conn = setup_conn();
+        let created = create_record(&conn, "Title", "Body").unwrap();
+        let id = created["id"].as_i64().unwrap();
+        let fetched = get_record(&conn, id).unwrap();
+        assert_eq!(fetched["title"], "Title");
+        assert_eq!(fetched["body"], "Body");
+    }
+
+    #[test]
+    fn delete_removes_record() {
+        let conn = setup_conn();
+        let created = create_record(&conn, "Title", "Body").unwrap();
+        let id = created["id"].as_i64().unwrap();
+        assert_eq!(delete_record(&conn, id).unwrap(), 1);
+        assert!(get_record(&conn, id).is_err());
+    }
+}
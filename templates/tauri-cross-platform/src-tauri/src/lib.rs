@@ -0,0 +1,503 @@
+mod db;
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use rusqlite::Connection;
+use serde::Deserialize;
+#[cfg(desktop)]
+use tauri::menu::{Menu, MenuBuilder, MenuItemBuilder, SubmenuBuilder};
+#[cfg(desktop)]
+use tauri::tray::TrayIconBuilder;
+#[cfg(desktop)]
+use tauri::WindowEvent;
+#[cfg(desktop)]
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri::{AppHandle, Emitter, Listener, Manager, WebviewWindow};
+
+// SQLite 接続を保持するアプリケーション状態
+struct DbConnection(Mutex<Connection>);
+
+// 現在登録されているグローバルショートカットを覚えておき、再登録時に解除できるようにする
+#[cfg(desktop)]
+struct GlobalShortcutState(Mutex<String>);
+
+#[cfg(desktop)]
+const DEFAULT_TOGGLE_SHORTCUT: &str = "CmdOrCtrl+Shift+Space";
+
+// コマンドハンドラー：フロントエンドから呼び出し可能
+#[tauri::command]
+fn greet(name: &str) -> String {
+    format!("Hello, {}! You've been greeted from Rust!", name)
+}
+
+// システム情報を取得するコマンド
+#[tauri::command]
+fn get_system_info() -> serde_json::Value {
+    serde_json::json!({
+        "platform": std::env::consts::OS,
+        "architecture": std::env::consts::ARCH,
+        "version": env!("CARGO_PKG_VERSION")
+    })
+}
+
+// read_file/write_file がアクセスできるベースディレクトリ。
+// フロントエンドから任意の絶対パスを渡させないよう、許可されたルートに限定する。
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+enum PathBase {
+    AppData,
+    Resource,
+    Config,
+}
+
+impl PathBase {
+    fn resolve_dir(&self, app: &AppHandle) -> Result<PathBuf, String> {
+        let resolver = app.path();
+        match self {
+            PathBase::AppData => resolver.app_data_dir(),
+            PathBase::Resource => resolver.resource_dir(),
+            PathBase::Config => resolver.app_config_dir(),
+        }
+        .map_err(|e| format!("ベースディレクトリの解決に失敗しました: {}", e))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ScopedPath {
+    base: PathBase,
+    relative: String,
+}
+
+// `base` 配下に正規化した絶対パスを組み立て、`..` やシンボリックリンクで
+// ルートの外に出ようとするパスを拒否する。
+fn resolve_scoped_path(app: &AppHandle, scoped: &ScopedPath) -> Result<PathBuf, String> {
+    let base_dir = scoped.base.resolve_dir(app)?;
+    std::fs::create_dir_all(&base_dir)
+        .map_err(|e| format!("ベースディレクトリの作成エラー: {}", e))?;
+    let canonical_base = base_dir
+        .canonicalize()
+        .map_err(|e| format!("ベースディレクトリの正規化エラー: {}", e))?;
+
+    sandbox_join(&canonical_base, &scoped.relative)
+}
+
+// `canonical_base`（既に正規化済みの絶対パス）配下に限定して `relative` を解決する。
+// `..` を含むパスはディレクトリを一切作成せずその場で拒否し、葉が既存のシンボリック
+// リンクでルートの外を指している場合も拒否する。`canonical_base` 自身は呼び出し側が
+// 正規化しておくこと。
+fn sandbox_join(canonical_base: &Path, relative: &str) -> Result<PathBuf, String> {
+    use std::path::Component;
+
+    if Path::new(relative)
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return Err("許可されたディレクトリの外部へのアクセスです".to_string());
+    }
+
+    let candidate = canonical_base.join(relative);
+    let parent = candidate
+        .parent()
+        .ok_or_else(|| "不正なパスです".to_string())?;
+    std::fs::create_dir_all(parent).map_err(|e| format!("ディレクトリ作成エラー: {}", e))?;
+    let canonical_parent = parent
+        .canonicalize()
+        .map_err(|e| format!("パスの正規化エラー: {}", e))?;
+    if !canonical_parent.starts_with(canonical_base) {
+        return Err("許可されたディレクトリの外部へのアクセスです".to_string());
+    }
+
+    let file_name = candidate
+        .file_name()
+        .ok_or_else(|| "不正なパスです".to_string())?;
+    let resolved = canonical_parent.join(file_name);
+
+    // 葉が既存のシンボリックリンクの場合、リンク先もサンドボックス内か確認する
+    // （存在しないパスはこの時点では許可 — write_file が新規作成するケース）。
+    if let Ok(canonical_resolved) = resolved.canonicalize() {
+        if !canonical_resolved.starts_with(canonical_base) {
+            return Err("許可されたディレクトリの外部へのアクセスです".to_string());
+        }
+    }
+
+    Ok(resolved)
+}
+
+// ファイル操作の例。パスは PathResolver が知る許可済みルート配下にのみ解決される。
+#[tauri::command]
+async fn read_file(app: AppHandle, request: ScopedPath) -> Result<String, String> {
+    let path = resolve_scoped_path(&app, &request)?;
+    std::fs::read_to_string(&path).map_err(|e| format!("ファイル読み込みエラー: {}", e))
+}
+
+#[tauri::command]
+async fn write_file(app: AppHandle, request: ScopedPath, content: String) -> Result<(), String> {
+    let path = resolve_scoped_path(&app, &request)?;
+    std::fs::write(&path, content).map_err(|e| format!("ファイル書き込みエラー: {}", e))
+}
+
+// i18n リソースの読み込みパターンに倣い、バンドルされた JSON リソースを
+// BaseDirectory::Resource で解決して読み込む。`..` や絶対パスを含む名前は拒否する。
+#[tauri::command]
+fn read_resource_json(app: AppHandle, name: String) -> Result<serde_json::Value, String> {
+    if Path::new(&name)
+        .components()
+        .any(|component| !matches!(component, std::path::Component::Normal(_)))
+    {
+        return Err("許可されたディレクトリの外部へのアクセスです".to_string());
+    }
+    let resource_path = app
+        .path()
+        .resolve(&name, tauri::path::BaseDirectory::Resource)
+        .map_err(|e| format!("リソースが見つかりません（{}）: {}", name, e))?;
+    let contents = std::fs::read_to_string(&resource_path)
+        .map_err(|e| format!("リソース読み込みエラー: {}", e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("JSON解析エラー: {}", e))
+}
+
+// records テーブルへの CRUD コマンド。実際のロジックは db モジュールにあり、
+// ここでは State からコネクションを取り出して委譲するだけ。
+#[tauri::command]
+fn create_record(
+    state: tauri::State<DbConnection>,
+    title: String,
+    body: String,
+) -> Result<serde_json::Value, String> {
+    let conn = state.0.lock().expect("DB接続のロックに失敗しました");
+    db::create_record(&conn, &title, &body).map_err(|e| format!("レコード作成エラー: {}", e))
+}
+
+#[tauri::command]
+fn list_records(state: tauri::State<DbConnection>) -> Result<serde_json::Value, String> {
+    let conn = state.0.lock().expect("DB接続のロックに失敗しました");
+    db::list_records(&conn).map_err(|e| format!("レコード一覧取得エラー: {}", e))
+}
+
+#[tauri::command]
+fn get_record(state: tauri::State<DbConnection>, id: i64) -> Result<serde_json::Value, String> {
+    let conn = state.0.lock().expect("DB接続のロックに失敗しました");
+    db::get_record(&conn, id).map_err(|e| format!("レコード取得エラー: {}", e))
+}
+
+#[tauri::command]
+fn delete_record(state: tauri::State<DbConnection>, id: i64) -> Result<serde_json::Value, String> {
+    let conn = state.0.lock().expect("DB接続のロックに失敗しました");
+    db::delete_record(&conn, id)
+        .map(|deleted| serde_json::json!({ "deleted": deleted }))
+        .map_err(|e| format!("レコード削除エラー: {}", e))
+}
+
+// フロントエンド→Rust→フロントエンドの往復を示すサンプルコマンド。
+// window.listen で "frontend-ping" を受け取り、折り返し "backend-pong" を emit する。
+#[tauri::command]
+fn ping_pong(window: WebviewWindow) {
+    let reply_window = window.clone();
+    window.listen("frontend-ping", move |event| {
+        println!("frontend-ping received: {:?}", event.payload());
+        reply_window
+            .emit("backend-pong", "pong")
+            .expect("backend-pongイベントの送信に失敗しました");
+    });
+}
+
+// アプリケーションメニューを構築する（File/Edit サブメニュー）。デスクトップのみ。
+#[cfg(desktop)]
+fn build_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let file_menu = SubmenuBuilder::new(app, "File")
+        .item(&MenuItemBuilder::with_id("new-file", "New").build(app)?)
+        .item(&MenuItemBuilder::with_id("open-file", "Open...").build(app)?)
+        .separator()
+        .item(
+            &MenuItemBuilder::with_id("hide-window", "Hide")
+                .accelerator("Escape")
+                .build(app)?,
+        )
+        .separator()
+        .item(&MenuItemBuilder::with_id("quit", "Quit").build(app)?)
+        .build()?;
+    let edit_menu = SubmenuBuilder::new(app, "Edit")
+        .undo()
+        .redo()
+        .separator()
+        .cut()
+        .copy()
+        .paste()
+        .build()?;
+
+    MenuBuilder::new(app).items(&[&file_menu, &edit_menu]).build()
+}
+
+// "quit" と "hide-window" はその場で処理し、それ以外のメニュー項目の選択を
+// "menu-event" としてフロントエンドへ転送する
+#[cfg(desktop)]
+fn handle_menu_event(app: &AppHandle, event: tauri::menu::MenuEvent) {
+    match event.id().as_ref() {
+        "quit" => app.exit(0),
+        "hide-window" => {
+            let window = app
+                .get_webview_window("main")
+                .expect("mainウィンドウが見つかりません");
+            window.hide().expect("ウィンドウの非表示に失敗しました");
+        }
+        id => {
+            app.emit("menu-event", id)
+                .expect("menu-eventイベントの送信に失敗しました");
+        }
+    }
+}
+
+// システムトレイを構築する（show/hide/quit）。デスクトップのみ。
+#[cfg(desktop)]
+fn build_tray(app: &AppHandle) -> tauri::Result<()> {
+    let tray_menu = MenuBuilder::new(app)
+        .item(&MenuItemBuilder::with_id("show", "Show").build(app)?)
+        .item(&MenuItemBuilder::with_id("hide", "Hide").build(app)?)
+        .separator()
+        .item(&MenuItemBuilder::with_id("quit", "Quit").build(app)?)
+        .build()?;
+
+    TrayIconBuilder::new()
+        .menu(&tray_menu)
+        .on_menu_event(|app, event| {
+            let window = app
+                .get_webview_window("main")
+                .expect("mainウィンドウが見つかりません");
+            match event.id().as_ref() {
+                "show" => {
+                    window.show().expect("ウィンドウの表示に失敗しました");
+                    window.set_focus().expect("ウィンドウのフォーカスに失敗しました");
+                }
+                "hide" => window.hide().expect("ウィンドウの非表示に失敗しました"),
+                "quit" => app.exit(0),
+                _ => {}
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+// メインウィンドウの表示状態に応じて表示/非表示を切り替える
+#[cfg(desktop)]
+fn toggle_main_window(app: &AppHandle) {
+    let window = app
+        .get_webview_window("main")
+        .expect("mainウィンドウが見つかりません");
+    if window.is_visible().unwrap_or(false) {
+        window.hide().expect("ウィンドウの非表示に失敗しました");
+    } else {
+        window.show().expect("ウィンドウの表示に失敗しました");
+        window.set_focus().expect("ウィンドウのフォーカスに失敗しました");
+    }
+}
+
+// フロントエンドからウィンドウ切り替え用のグローバルショートカットを再登録するコマンド。
+// 既存の登録を解除してから新しいアクセラレータを登録する。デスクトップのみ。
+#[cfg(desktop)]
+#[tauri::command]
+fn set_global_shortcut(app: AppHandle, accelerator: String) -> Result<(), String> {
+    let state = app.state::<GlobalShortcutState>();
+    let mut current = state.0.lock().expect("GlobalShortcutStateのロックに失敗しました");
+
+    let current_shortcut: Shortcut = current
+        .as_str()
+        .parse()
+        .map_err(|e| format!("現在のショートカットの解析エラー: {}", e))?;
+    app.global_shortcut()
+        .unregister(current_shortcut)
+        .map_err(|e| format!("ショートカット解除エラー: {}", e))?;
+
+    let new_shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("ショートカット解析エラー: {}", e))?;
+    app.global_shortcut()
+        .register(new_shortcut)
+        .map_err(|e| format!("ショートカット登録エラー: {}", e))?;
+
+    *current = accelerator;
+    Ok(())
+}
+
+// メニュー・グローバルショートカット・ウィンドウクローズ確認など、デスクトップにしか
+// 存在しない機能を Builder に追加する（トレイは setup 内の build_tray で構築する）。
+// モバイルターゲットではこれらを一切 wiring しない。
+#[cfg(desktop)]
+fn configure_desktop_features(
+    builder: tauri::Builder<tauri::Wry>,
+) -> tauri::Builder<tauri::Wry> {
+    builder
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, _shortcut, event| {
+                    if event.state() == ShortcutState::Pressed {
+                        toggle_main_window(app);
+                    }
+                })
+                .build(),
+        )
+        .manage(GlobalShortcutState(Mutex::new(DEFAULT_TOGGLE_SHORTCUT.to_string())))
+        .menu(build_menu)
+        .on_menu_event(handle_menu_event)
+        .on_window_event(|window, event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                // 即座に終了せず、フロントエンドに確認を委ねる
+                api.prevent_close();
+                window
+                    .emit("confirm-close", ())
+                    .expect("confirm-closeイベントの送信に失敗しました");
+            }
+        })
+}
+
+// Esc はここではグローバル登録しない。グローバルショートカットは OS 全体に対して
+// キーを奪ってしまうため、フォーカスの有無に関わらず他のアプリの Esc 入力まで
+// 横取りしてしまう。Esc はウィンドウ/アプリにフォーカスがある時だけ効けばよいので、
+// build_menu の "hide-window" 項目にメニューアクセラレータとして持たせている。
+#[cfg(desktop)]
+fn register_default_global_shortcut(app: &AppHandle) {
+    let shortcut: Shortcut = DEFAULT_TOGGLE_SHORTCUT
+        .parse()
+        .expect("デフォルトのグローバルショートカットの解析に失敗しました");
+    app.global_shortcut()
+        .register(shortcut)
+        .expect("デフォルトのグローバルショートカット登録に失敗しました");
+}
+
+// デスクトップとモバイルの両方から呼ばれるアプリのエントリポイント。
+// モバイルビルドでは `tauri::mobile_entry_point` としてネイティブ側から起動される。
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    let builder = tauri::Builder::default().invoke_handler(tauri::generate_handler![
+        greet,
+        get_system_info,
+        read_file,
+        write_file,
+        read_resource_json,
+        create_record,
+        list_records,
+        get_record,
+        delete_record,
+        ping_pong,
+        #[cfg(desktop)]
+        set_global_shortcut
+    ]);
+
+    #[cfg(desktop)]
+    let builder = configure_desktop_features(builder);
+
+    builder
+        .setup(|app| {
+            // アプリケーション起動時の初期化処理
+            println!("Tauri app is starting up...");
+
+            // アプリデータディレクトリ配下に SQLite データベースを用意し、State として登録する
+            let app_data_dir = app
+                .path()
+                .app_data_dir()
+                .expect("アプリデータディレクトリの解決に失敗しました");
+            std::fs::create_dir_all(&app_data_dir)
+                .expect("アプリデータディレクトリの作成に失敗しました");
+            let db_connection = Connection::open(app_data_dir.join("app.db"))
+                .expect("データベース接続のオープンに失敗しました");
+            db::init_db(&db_connection).expect("データベース初期化に失敗しました");
+            app.manage(DbConnection(Mutex::new(db_connection)));
+
+            #[cfg(desktop)]
+            build_tray(app.handle())?;
+
+            // バックグラウンドタスクからフロントエンドへ進捗イベントを push する。
+            // ポーリングせずに app.emit で状態変化を通知できる。
+            let app_handle = app.handle().clone();
+            thread::spawn(move || {
+                for progress in 0..=100 {
+                    app_handle
+                        .emit("progress-update", progress)
+                        .expect("progress-updateイベントの送信に失敗しました");
+                    thread::sleep(Duration::from_millis(50));
+                }
+            });
+
+            // どこからでもアプリを呼び出せるよう、デフォルトのグローバルショートカットを登録する
+            #[cfg(desktop)]
+            register_default_global_shortcut(app.handle());
+
+            Ok(())
+        })
+        .run(tauri::generate_context!())
+        .expect("Tauriアプリケーションの実行エラー");
+}
+
+#[cfg(test)]
+mod sandbox_tests {
+    use super::*;
+    use std::fs;
+
+    // OS の一時ディレクトリ配下に専用のサンドボックスを作り、テスト後に削除する。
+    struct TempSandbox {
+        path: PathBuf,
+    }
+
+    impl TempSandbox {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "fluorite-flake-sandbox-test-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self {
+                path: path.canonicalize().unwrap(),
+            }
+        }
+    }
+
+    impl Drop for TempSandbox {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn rejects_parent_dir_traversal_without_touching_disk() {
+        let sandbox = TempSandbox::new("traversal");
+        let escape_target = sandbox.path.parent().unwrap().join("fluorite-flake-escaped");
+        let _ = fs::remove_dir_all(&escape_target);
+
+        let result = sandbox_join(&sandbox.path, "../fluorite-flake-escaped/evil.txt");
+
+        assert!(result.is_err());
+        assert!(!escape_target.exists());
+    }
+
+    #[test]
+    fn accepts_nested_relative_path() {
+        let sandbox = TempSandbox::new("nested");
+
+        let resolved = sandbox_join(&sandbox.path, "a/b/c.txt").unwrap();
+
+        assert!(resolved.starts_with(&sandbox.path));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlink_leaf_escaping_base() {
+        let sandbox = TempSandbox::new("symlink-leaf");
+        let outside = std::env::temp_dir().join(format!(
+            "fluorite-flake-sandbox-outside-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&outside);
+        fs::create_dir_all(&outside).unwrap();
+        let secret = outside.join("secret.txt");
+        fs::write(&secret, "top secret").unwrap();
+        std::os::unix::fs::symlink(&secret, sandbox.path.join("escape.txt")).unwrap();
+
+        let result = sandbox_join(&sandbox.path, "escape.txt");
+
+        let _ = fs::remove_dir_all(&outside);
+        assert!(result.is_err());
+    }
+}